@@ -0,0 +1,117 @@
+//! Pluggable arithmetic backends for the hot loops in [`crate::algebra`]: the NTT
+//! layers and the pointwise multiply they enable. An AVX2 implementation is used when
+//! it was compiled in and the host CPU supports it (detected once at runtime via
+//! `cpufeatures`, the same approach RustCrypto's `polyval` uses for its PCLMULQDQ
+//! backend); every call site otherwise falls back to the portable scalar path below.
+//! Both implement [`Backend`] so callers don't need to know which is active, and the
+//! two are required to agree bit-for-bit (see the `avx2` submodule's tests).
+
+use super::{FieldElement, INV_NTT_SCALE, N, ZETAS};
+use hybrid_array::{typenum::U256, Array};
+
+#[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+mod avx2;
+
+/// Arithmetic a backend must provide over a full 256-coefficient ring element.
+pub(crate) trait Backend {
+    fn ntt(a: &mut Array<FieldElement, U256>);
+    fn inv_ntt(a: &mut Array<FieldElement, U256>);
+    fn pointwise_mul(
+        a: &Array<FieldElement, U256>,
+        b: &Array<FieldElement, U256>,
+    ) -> Array<FieldElement, U256>;
+}
+
+/// Portable implementation; always available, and the reference the AVX2 backend is
+/// checked against.
+pub(crate) struct Scalar;
+
+impl Backend for Scalar {
+    fn ntt(a: &mut Array<FieldElement, U256>) {
+        let mut k = 0;
+        let mut len = 128;
+        while len >= 1 {
+            let mut start = 0;
+            while start < N {
+                k += 1;
+                let zeta = FieldElement(ZETAS[k]);
+                for j in start..start + len {
+                    let t = FieldElement::montgomery_mul(zeta, a[j + len]);
+                    a[j + len] = a[j] - t;
+                    a[j] = a[j] + t;
+                }
+                start += 2 * len;
+            }
+            len /= 2;
+        }
+    }
+
+    fn inv_ntt(a: &mut Array<FieldElement, U256>) {
+        let mut k = N;
+        let mut len = 1;
+        while len < N {
+            let mut start = 0;
+            while start < N {
+                k -= 1;
+                let zeta = FieldElement(FieldElement::Q - ZETAS[k]);
+                for j in start..start + len {
+                    let t = a[j];
+                    a[j] = t + a[j + len];
+                    a[j + len] = FieldElement::montgomery_mul(zeta, t - a[j + len]);
+                }
+                start += 2 * len;
+            }
+            len *= 2;
+        }
+
+        let scale = FieldElement(INV_NTT_SCALE);
+        for x in a.iter_mut() {
+            *x = FieldElement::montgomery_mul(*x, scale);
+        }
+    }
+
+    fn pointwise_mul(
+        a: &Array<FieldElement, U256>,
+        b: &Array<FieldElement, U256>,
+    ) -> Array<FieldElement, U256> {
+        a.iter()
+            .zip(b.iter())
+            .map(|(&x, &y)| FieldElement::montgomery_mul(x, y))
+            .collect()
+    }
+}
+
+#[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+cpufeatures::new!(avx2_cpuid, "avx2");
+
+/// Runs the forward NTT in place, dispatching to the AVX2 backend if it was compiled
+/// in and the host supports it.
+pub(crate) fn ntt(a: &mut Array<FieldElement, U256>) {
+    #[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+    if avx2_cpuid::init().get() {
+        return avx2::Avx2::ntt(a);
+    }
+    Scalar::ntt(a)
+}
+
+/// Runs the inverse NTT in place. Backend selection mirrors [`ntt`].
+pub(crate) fn inv_ntt(a: &mut Array<FieldElement, U256>) {
+    #[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+    if avx2_cpuid::init().get() {
+        return avx2::Avx2::inv_ntt(a);
+    }
+    Scalar::inv_ntt(a)
+}
+
+/// Computes a coefficient-wise product of two NTT-domain polynomials. Backend
+/// selection mirrors [`ntt`].
+pub(crate) fn pointwise_mul(
+    a: &Array<FieldElement, U256>,
+    b: &Array<FieldElement, U256>,
+) -> Array<FieldElement, U256> {
+    #[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+    if avx2_cpuid::init().get() {
+        return avx2::Avx2::pointwise_mul(a, b);
+    }
+    Scalar::pointwise_mul(a, b)
+}
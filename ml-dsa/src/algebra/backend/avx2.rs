@@ -0,0 +1,269 @@
+//! AVX2 implementation of [`super::Backend`], packing eight `FieldElement`s into one
+//! `__m256i` lane. Only ever invoked by [`super::ntt`]/[`super::inv_ntt`]/
+//! [`super::pointwise_mul`] after `cpufeatures` has confirmed the host supports AVX2,
+//! which is what makes the `unsafe fn`s below sound to call.
+
+use super::super::{FieldElement, INV_NTT_SCALE, N, ZETAS};
+use super::Backend;
+use core::arch::x86_64::*;
+use hybrid_array::{typenum::U256, Array};
+
+/// Coefficients packed per `__m256i`.
+const LANES: usize = 8;
+
+/// Vectorized analogue of `FieldElement::montgomery_mul`, applied lane-wise to eight
+/// packed coefficients. 32x32 -> 64 widening multiplies come in pairs (AVX2 has no
+/// single instruction that multiplies all eight 32-bit lanes at once): one pass over
+/// the lanes as given, one over them shifted down by 32 bits, each using
+/// `_mm256_mul_epu32`, which reads the low 32 bits of every 64-bit lane of its inputs.
+#[target_feature(enable = "avx2")]
+unsafe fn montgomery_mul(a: __m256i, b: __m256i) -> __m256i {
+    let qinv = _mm256_set1_epi32(FieldElement::QINV as i32);
+    let q = _mm256_set1_epi32(FieldElement::Q as i32);
+    let q_minus_one = _mm256_set1_epi32(FieldElement::Q as i32 - 1);
+
+    let prod_evn = _mm256_mul_epu32(a, b);
+    let prod_odd = _mm256_mul_epu32(_mm256_srli_epi64(a, 32), _mm256_srli_epi64(b, 32));
+
+    let t_evn = _mm256_mul_epu32(prod_evn, qinv);
+    let t_odd = _mm256_mul_epu32(prod_odd, qinv);
+
+    let r_evn = _mm256_srli_epi64(_mm256_sub_epi64(prod_evn, _mm256_mul_epu32(t_evn, q)), 32);
+    let r_odd = _mm256_srli_epi64(_mm256_sub_epi64(prod_odd, _mm256_mul_epu32(t_odd, q)), 32);
+
+    // Re-interleave the even/odd 32-bit results into one packed vector of 8 lanes.
+    let r = _mm256_or_si256(r_evn, _mm256_slli_epi64(r_odd, 32));
+
+    // `t` was taken as unsigned, so each lane holds `(prod - t*Q) / 2^32` as a signed
+    // value in `(-q, q)`: add Q once to every negative lane, like `caddq`.
+    let neg = _mm256_cmpgt_epi32(_mm256_setzero_si256(), r);
+    let r = _mm256_add_epi32(r, _mm256_and_si256(neg, q));
+
+    // `small_reduce`: subtract Q once from any lane that's still >= Q. Compares
+    // against `q - 1` with `cmpgt` (no `cmpge` exists) so a lane landing at exactly
+    // Q is still folded down to 0.
+    let over = _mm256_cmpgt_epi32(r, q_minus_one);
+    _mm256_sub_epi32(r, _mm256_and_si256(over, q))
+}
+
+/// Vectorized, lazily-reduced `FieldElement` add.
+#[target_feature(enable = "avx2")]
+unsafe fn add(a: __m256i, b: __m256i) -> __m256i {
+    let q = _mm256_set1_epi32(FieldElement::Q as i32);
+    let q_minus_one = _mm256_set1_epi32(FieldElement::Q as i32 - 1);
+    let sum = _mm256_add_epi32(a, b);
+    let over = _mm256_cmpgt_epi32(sum, q_minus_one);
+    _mm256_sub_epi32(sum, _mm256_and_si256(over, q))
+}
+
+/// Vectorized, lazily-reduced `FieldElement` sub.
+#[target_feature(enable = "avx2")]
+unsafe fn sub(a: __m256i, b: __m256i) -> __m256i {
+    let q = _mm256_set1_epi32(FieldElement::Q as i32);
+    let q_minus_one = _mm256_set1_epi32(FieldElement::Q as i32 - 1);
+    let diff = _mm256_add_epi32(_mm256_sub_epi32(a, b), q);
+    let over = _mm256_cmpgt_epi32(diff, q_minus_one);
+    _mm256_sub_epi32(diff, _mm256_and_si256(over, q))
+}
+
+pub(crate) struct Avx2;
+
+impl Backend for Avx2 {
+    fn ntt(a: &mut Array<FieldElement, U256>) {
+        // SAFETY: only reachable once `cpufeatures` has confirmed AVX2 support (see
+        // `super::avx2_cpuid`'s callers), which is all `#[target_feature(enable =
+        // "avx2")]` requires of its caller.
+        unsafe { ntt_avx2(a) }
+    }
+
+    fn inv_ntt(a: &mut Array<FieldElement, U256>) {
+        // SAFETY: see `ntt` above.
+        unsafe { inv_ntt_avx2(a) }
+    }
+
+    fn pointwise_mul(
+        a: &Array<FieldElement, U256>,
+        b: &Array<FieldElement, U256>,
+    ) -> Array<FieldElement, U256> {
+        // SAFETY: see `ntt` above.
+        unsafe { pointwise_mul_avx2(a, b) }
+    }
+}
+
+/// For layers with `len >= LANES`, every butterfly pair `(j, j+len)` inside a
+/// `start..start+len` block shares one zeta, so a whole `__m256i` of 8 coefficients
+/// can go through one vectorized butterfly. Below that (`len` = 4, 2, 1) a single
+/// `__m256i` spans coefficients under different zetas, which needs per-lane shuffles
+/// to vectorize cleanly; those last three layers fall back to the scalar butterfly.
+#[target_feature(enable = "avx2")]
+unsafe fn ntt_avx2(a: &mut Array<FieldElement, U256>) {
+    let ptr = a.as_mut_ptr() as *mut i32;
+    let mut k = 0usize;
+    let mut len = 128;
+    while len >= LANES {
+        let mut start = 0;
+        while start < N {
+            k += 1;
+            let zeta = _mm256_set1_epi32(ZETAS[k] as i32);
+            let mut j = start;
+            while j < start + len {
+                let aj = _mm256_loadu_si256(ptr.add(j) as *const __m256i);
+                let ajl = _mm256_loadu_si256(ptr.add(j + len) as *const __m256i);
+                let t = montgomery_mul(zeta, ajl);
+                _mm256_storeu_si256(ptr.add(j + len) as *mut __m256i, sub(aj, t));
+                _mm256_storeu_si256(ptr.add(j) as *mut __m256i, add(aj, t));
+                j += LANES;
+            }
+            start += 2 * len;
+        }
+        len /= 2;
+    }
+    while len >= 1 {
+        let mut start = 0;
+        while start < N {
+            k += 1;
+            let zeta = FieldElement(ZETAS[k]);
+            for j in start..start + len {
+                let t = FieldElement::montgomery_mul(zeta, a[j + len]);
+                a[j + len] = a[j] - t;
+                a[j] = a[j] + t;
+            }
+            start += 2 * len;
+        }
+        len /= 2;
+    }
+}
+
+/// Mirror image of [`ntt_avx2`]: the small layers with per-lane zetas run scalar
+/// first, then the rest vectorize once `len >= LANES`.
+#[target_feature(enable = "avx2")]
+unsafe fn inv_ntt_avx2(a: &mut Array<FieldElement, U256>) {
+    let mut k = N;
+    let mut len = 1;
+    while len < LANES {
+        let mut start = 0;
+        while start < N {
+            k -= 1;
+            let zeta = FieldElement(FieldElement::Q - ZETAS[k]);
+            for j in start..start + len {
+                let t = a[j];
+                a[j] = t + a[j + len];
+                a[j + len] = FieldElement::montgomery_mul(zeta, t - a[j + len]);
+            }
+            start += 2 * len;
+        }
+        len *= 2;
+    }
+
+    let ptr = a.as_mut_ptr() as *mut i32;
+    while len < N {
+        let mut start = 0;
+        while start < N {
+            k -= 1;
+            let zeta = _mm256_set1_epi32((FieldElement::Q - ZETAS[k]) as i32);
+            let mut j = start;
+            while j < start + len {
+                let aj = _mm256_loadu_si256(ptr.add(j) as *const __m256i);
+                let ajl = _mm256_loadu_si256(ptr.add(j + len) as *const __m256i);
+                _mm256_storeu_si256(ptr.add(j) as *mut __m256i, add(aj, ajl));
+                let t = montgomery_mul(zeta, sub(aj, ajl));
+                _mm256_storeu_si256(ptr.add(j + len) as *mut __m256i, t);
+                j += LANES;
+            }
+            start += 2 * len;
+        }
+        len *= 2;
+    }
+
+    let scale = _mm256_set1_epi32(INV_NTT_SCALE as i32);
+    for chunk in 0..N / LANES {
+        let p = ptr.add(chunk * LANES);
+        let v = _mm256_loadu_si256(p as *const __m256i);
+        _mm256_storeu_si256(p as *mut __m256i, montgomery_mul(v, scale));
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn pointwise_mul_avx2(
+    a: &Array<FieldElement, U256>,
+    b: &Array<FieldElement, U256>,
+) -> Array<FieldElement, U256> {
+    let mut out = Array::<FieldElement, U256>::default();
+    let ap = a.as_ptr() as *const i32;
+    let bp = b.as_ptr() as *const i32;
+    let op = out.as_mut_ptr() as *mut i32;
+    for chunk in 0..N / LANES {
+        let av = _mm256_loadu_si256(ap.add(chunk * LANES) as *const __m256i);
+        let bv = _mm256_loadu_si256(bp.add(chunk * LANES) as *const __m256i);
+        _mm256_storeu_si256(op.add(chunk * LANES) as *mut __m256i, montgomery_mul(av, bv));
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebra::backend::Scalar;
+
+    /// Deterministic, dependency-free stand-in for a random `FieldElement` array, so
+    /// this test doesn't need a `rand` dependency just to compare two backends.
+    fn pseudo_random_coeffs(seed: u32) -> Array<FieldElement, U256> {
+        let mut state = seed.wrapping_add(0x9E37_79B9);
+        (0..N)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                FieldElement(state % FieldElement::Q)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn avx2_add_and_sub_canonicalize_at_q() {
+        if !super::super::avx2_cpuid::init().get() {
+            return;
+        }
+
+        // Mirrors the scalar `small_reduce_canonicalizes_at_q` test: both operations
+        // land on exactly Q internally and must fold that to 0, not leave it as Q.
+        unsafe {
+            let five = _mm256_set1_epi32(5);
+            let q_minus_five = _mm256_set1_epi32((FieldElement::Q - 5) as i32);
+            let mut sum = [0i32; LANES];
+            _mm256_storeu_si256(sum.as_mut_ptr() as *mut __m256i, add(five, q_minus_five));
+            assert_eq!(sum, [0; LANES]);
+
+            let mut diff = [0i32; LANES];
+            _mm256_storeu_si256(diff.as_mut_ptr() as *mut __m256i, sub(five, five));
+            assert_eq!(diff, [0; LANES]);
+        }
+    }
+
+    #[test]
+    fn avx2_matches_scalar() {
+        if !super::super::avx2_cpuid::init().get() {
+            return;
+        }
+
+        let a = pseudo_random_coeffs(1);
+        let b = pseudo_random_coeffs(2);
+
+        let mut ntt_scalar = a;
+        Scalar::ntt(&mut ntt_scalar);
+        let mut ntt_avx2 = a;
+        Avx2::ntt(&mut ntt_avx2);
+        assert_eq!(ntt_scalar, ntt_avx2);
+
+        let mut inv_scalar = ntt_scalar;
+        Scalar::inv_ntt(&mut inv_scalar);
+        let mut inv_avx2 = ntt_avx2;
+        Avx2::inv_ntt(&mut inv_avx2);
+        assert_eq!(inv_scalar, inv_avx2);
+
+        assert_eq!(
+            Scalar::pointwise_mul(&a, &b),
+            Avx2::pointwise_mul(&a, &b)
+        );
+    }
+}